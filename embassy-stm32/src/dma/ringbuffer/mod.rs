@@ -3,6 +3,8 @@
 use core::future::poll_fn;
 use core::task::{Poll, Waker};
 
+use embassy_time::Instant;
+
 use crate::dma::word::Word;
 
 pub trait DmaCtrl {
@@ -58,6 +60,12 @@ impl DmaIndex {
         self.pos = next % cap;
     }
 
+    fn retreat(&mut self, cap: usize, steps: usize) {
+        let prev = (self.completion_count * cap + self.pos).saturating_sub(steps);
+        self.completion_count = prev / cap;
+        self.pos = prev % cap;
+    }
+
     fn normalize(lhs: &mut DmaIndex, rhs: &mut DmaIndex) {
         let min_count = lhs.completion_count.min(rhs.completion_count);
         lhs.completion_count -= min_count;
@@ -73,6 +81,10 @@ pub struct ReadableDmaRingBuffer<'a, W: Word> {
     dma_buf: &'a mut [W],
     write_index: DmaIndex,
     read_index: DmaIndex,
+    /// Timestamp and `write_index` captured by the most recent `len` call while a sample period is
+    /// set, used by `sample_time`.
+    last_sync: (u64, DmaIndex),
+    sample_period_us: u64,
 }
 
 impl<'a, W: Word> ReadableDmaRingBuffer<'a, W> {
@@ -82,9 +94,19 @@ impl<'a, W: Word> ReadableDmaRingBuffer<'a, W> {
             dma_buf,
             write_index: Default::default(),
             read_index: Default::default(),
+            last_sync: (0, Default::default()),
+            sample_period_us: 0,
         }
     }
 
+    /// Set the fixed time between consecutive samples, in microseconds.
+    ///
+    /// This must be set for [`sample_time`](Self::sample_time) to return meaningful results; it
+    /// defaults to 0.
+    pub fn set_sample_period_us(&mut self, period_us: u64) {
+        self.sample_period_us = period_us;
+    }
+
     /// Reset the ring buffer to its initial state.
     pub fn clear(&mut self, dma: &mut impl DmaCtrl) {
         dma.reset_complete_count();
@@ -103,6 +125,12 @@ impl<'a, W: Word> ReadableDmaRingBuffer<'a, W> {
         self.write_index.dma_sync(self.cap(), dma);
         DmaIndex::normalize(&mut self.write_index, &mut self.read_index);
 
+        // Only pay for a clock read when timestamping is actually in use, and capture it after
+        // `normalize` so `last_sync.1` shares a reference frame with `read_index`.
+        if self.sample_period_us != 0 {
+            self.last_sync = (Instant::now().as_micros(), self.write_index);
+        }
+
         let diff: usize = self.write_index.diff(self.cap(), &self.read_index).try_into().unwrap();
 
         if diff > self.cap() {
@@ -167,6 +195,23 @@ impl<'a, W: Word> ReadableDmaRingBuffer<'a, W> {
         Ok((readable, available - readable))
     }
 
+    /// Estimate the capture instant, in microseconds since the time driver's epoch, of the
+    /// readable element at `offset` (as passed to [`read_buf`](Self::read_buf)).
+    ///
+    /// This back-computes from the DMA position and timestamp captured on the most recent
+    /// [`len`](Self::len) call, using the sample period set via
+    /// [`set_sample_period_us`](Self::set_sample_period_us).
+    pub fn sample_time(&self, offset: usize) -> u64 {
+        let cap = self.cap();
+        let element = (self.read_index.completion_count * cap + self.read_index.pos + offset) as i64;
+
+        let (sync_time, sync_index) = self.last_sync;
+        let synced = (sync_index.completion_count * cap + sync_index.pos) as i64;
+
+        let elements_ago = synced - element;
+        (sync_time as i64 - elements_ago * self.sample_period_us as i64) as u64
+    }
+
     fn read_buf(&self, offset: usize) -> W {
         unsafe {
             core::ptr::read_volatile(
@@ -176,6 +221,75 @@ impl<'a, W: Word> ReadableDmaRingBuffer<'a, W> {
             )
         }
     }
+
+    /// Borrow the currently readable region of the underlying DMA buffer without copying it.
+    ///
+    /// Returns up to two contiguous slices: the first starting at the current read position,
+    /// and the second (possibly empty) covering the wrap-around tail at the start of the buffer.
+    /// After processing the data in place, call [`consume`](Self::consume) with the number of
+    /// elements handled to advance the read position.
+    pub fn read_slices(&mut self, dma: &mut impl DmaCtrl) -> Result<(&[W], &[W]), OverrunError> {
+        let readable = self.len(dma)?;
+        let cap = self.cap();
+        let start = self.read_index.as_index(cap, 0);
+
+        let (wrap, rest) = self.dma_buf.split_at(start);
+        let first_len = readable.min(rest.len());
+        let first = &rest[..first_len];
+        let second = &wrap[..readable - first_len];
+
+        Ok((first, second))
+    }
+
+    /// Advance the read position by `n` elements, as counted across the slices returned by the
+    /// preceding call to [`read_slices`](Self::read_slices).
+    ///
+    /// `n` is clamped to the number of elements currently readable, so it can never advance past
+    /// the DMA write position. OverrunError is returned if the consumed region was overwritten by
+    /// the DMA controller in the meantime, in which case the ring buffer automatically clears
+    /// itself, as with [`read`](Self::read).
+    pub fn consume(&mut self, dma: &mut impl DmaCtrl, n: usize) -> Result<(), OverrunError> {
+        let result = match self.len(dma) {
+            Ok(readable) => {
+                self.read_index.advance(self.cap(), n.min(readable));
+                self.len(dma).map(|_| ())
+            }
+            Err(e) => Err(e),
+        };
+        result.inspect_err(|_e| {
+            self.clear(dma);
+        })
+    }
+
+    /// Read up to `buf.len()` elements from the ring buffer, catching up to the freshest data
+    /// instead of erroring out when the consumer has fallen behind.
+    ///
+    /// Without an overrun, this reads from the current read position, as [`read`](Self::read)
+    /// does. Unlike [`read`](Self::read), an overrun never discards the whole buffer. Instead, the
+    /// read position is fast-forwarded to keep the newest `buf.len()`-bounded window of samples.
+    /// Returns a tuple of the number of elements copied into `buf` and the number of elements
+    /// that were skipped over to catch up (zero if there was no overrun).
+    pub fn read_latest(&mut self, dma: &mut impl DmaCtrl, buf: &mut [W]) -> Result<(usize, usize), OverrunError> {
+        let cap = self.cap();
+
+        let skipped = match self.len(dma) {
+            Ok(_) => 0,
+            Err(OverrunError) => {
+                let stale_read_index = self.read_index;
+                self.read_index = self.write_index;
+                self.read_index.retreat(cap, buf.len().min(cap));
+                self.read_index.diff(cap, &stale_read_index).max(0) as usize
+            }
+        };
+
+        let readable = self.len(dma)?.min(buf.len());
+        for i in 0..readable {
+            buf[i] = self.read_buf(i);
+        }
+        self.read_index.advance(cap, readable);
+
+        Ok((readable, skipped))
+    }
 }
 
 pub struct WritableDmaRingBuffer<'a, W: Word> {
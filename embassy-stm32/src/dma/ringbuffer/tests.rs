@@ -0,0 +1,137 @@
+use super::*;
+
+/// A fake DMA controller driven directly by the tests: `advance` simulates the DMA writer moving
+/// forward (and wrapping) by the given number of elements.
+struct TestCtrl {
+    cap: usize,
+    pos: usize,
+    completed: usize,
+}
+
+impl TestCtrl {
+    fn new(cap: usize) -> Self {
+        Self {
+            cap,
+            pos: 0,
+            completed: 0,
+        }
+    }
+
+    fn advance(&mut self, steps: usize) {
+        let next = self.pos + steps;
+        self.completed += next / self.cap;
+        self.pos = next % self.cap;
+    }
+}
+
+impl DmaCtrl for TestCtrl {
+    fn get_remaining_transfers(&self) -> usize {
+        self.cap - self.pos
+    }
+
+    fn reset_complete_count(&mut self) -> usize {
+        let completed = self.completed;
+        self.completed = 0;
+        completed
+    }
+
+    fn set_waker(&mut self, _waker: &Waker) {}
+}
+
+#[test]
+fn read_slices_splits_at_the_buffer_wrap() {
+    let mut buf = [0u8; 4];
+    let mut rb = ReadableDmaRingBuffer::new(&mut buf);
+    let mut ctrl = TestCtrl::new(4);
+
+    ctrl.advance(3);
+    let (first, second) = rb.read_slices(&mut ctrl).unwrap();
+    assert_eq!(first.len(), 3);
+    assert_eq!(second.len(), 0);
+    rb.consume(&mut ctrl, 3).unwrap();
+
+    ctrl.advance(2);
+    let (first, second) = rb.read_slices(&mut ctrl).unwrap();
+    assert_eq!(first.len(), 1);
+    assert_eq!(second.len(), 1);
+    rb.consume(&mut ctrl, 2).unwrap();
+
+    assert_eq!(rb.len(&mut ctrl).unwrap(), 0);
+}
+
+#[test]
+fn consume_clamps_to_the_readable_length() {
+    let mut buf = [0u8; 4];
+    let mut rb = ReadableDmaRingBuffer::new(&mut buf);
+    let mut ctrl = TestCtrl::new(4);
+
+    ctrl.advance(2);
+    assert_eq!(rb.len(&mut ctrl).unwrap(), 2);
+
+    // Asking to consume more than is readable must not panic or overtake the DMA writer.
+    rb.consume(&mut ctrl, 10).unwrap();
+    assert_eq!(rb.len(&mut ctrl).unwrap(), 0);
+}
+
+#[test]
+fn read_latest_behaves_like_read_when_there_is_no_overrun() {
+    let mut buf = [0u8; 8];
+    let mut rb = ReadableDmaRingBuffer::new(&mut buf);
+    let mut ctrl = TestCtrl::new(8);
+
+    ctrl.advance(3);
+    let mut out = [0u8; 8];
+    let (read, skipped) = rb.read_latest(&mut ctrl, &mut out).unwrap();
+    assert_eq!(read, 3);
+    assert_eq!(skipped, 0);
+}
+
+#[test]
+fn read_latest_catches_up_after_an_overrun_and_reports_skipped() {
+    let mut buf = [0u8; 4];
+    let mut rb = ReadableDmaRingBuffer::new(&mut buf);
+    let mut ctrl = TestCtrl::new(4);
+
+    // The DMA writer gets more than a full buffer ahead of the read position before the consumer
+    // catches up.
+    ctrl.advance(7);
+    let mut out = [0u8; 2];
+    let (read, skipped) = rb.read_latest(&mut ctrl, &mut out).unwrap();
+    assert_eq!(read, 2);
+    assert_eq!(skipped, 5);
+
+    // Having caught up, the ring buffer is consistent with the DMA writer again.
+    assert_eq!(rb.len(&mut ctrl).unwrap(), 0);
+}
+
+#[test]
+fn sample_time_estimates_capture_instant_from_the_sample_rate() {
+    let mut buf = [0u8; 8];
+    let mut rb = ReadableDmaRingBuffer::new(&mut buf);
+    let mut ctrl = TestCtrl::new(8);
+    rb.set_sample_period_us(100);
+
+    ctrl.advance(5);
+    rb.len(&mut ctrl).unwrap();
+
+    // Samples further back in the readable window were captured earlier, spaced apart by the
+    // configured sample period. Compare offsets rather than asserting an absolute timestamp,
+    // since the latter is tied to the real clock at the time the test ran.
+    let t0 = rb.sample_time(0);
+    let t4 = rb.sample_time(4);
+    assert_eq!(t4 - t0, 4 * 100);
+}
+
+#[test]
+fn sample_time_is_zero_without_a_configured_sample_period() {
+    let mut buf = [0u8; 8];
+    let mut rb = ReadableDmaRingBuffer::new(&mut buf);
+    let mut ctrl = TestCtrl::new(8);
+
+    ctrl.advance(5);
+    rb.len(&mut ctrl).unwrap();
+
+    // Timestamping is opt-in: without a sample period, `len` never reads the clock, so
+    // `sample_time` just reports the zeroed default.
+    assert_eq!(rb.sample_time(0), 0);
+}
@@ -4,12 +4,8 @@ use std::sync::{Condvar, Mutex, Once};
 use std::time::{Duration as StdDuration, Instant as StdInstant};
 use std::{mem, ptr, thread};
 
-use atomic_polyfill::{AtomicU8, Ordering};
-
 use crate::driver::{AlarmHandle, Driver};
 
-const ALARM_COUNT: usize = 4;
-
 struct AlarmState {
     timestamp: u64,
 
@@ -32,18 +28,13 @@ impl AlarmState {
 }
 
 struct TimeDriver {
-    alarm_count: AtomicU8,
-
     once: Once,
-    alarms: UninitCell<Mutex<[AlarmState; ALARM_COUNT]>>,
+    alarms: UninitCell<Mutex<Vec<AlarmState>>>,
     zero_instant: UninitCell<StdInstant>,
     signaler: UninitCell<Signaler>,
 }
 
-const ALARM_NEW: AlarmState = AlarmState::new();
 crate::time_driver_impl!(static DRIVER: TimeDriver = TimeDriver {
-    alarm_count: AtomicU8::new(0),
-
     once: Once::new(),
     alarms: UninitCell::uninit(),
     zero_instant: UninitCell::uninit(),
@@ -53,7 +44,7 @@ crate::time_driver_impl!(static DRIVER: TimeDriver = TimeDriver {
 impl TimeDriver {
     fn init(&self) {
         self.once.call_once(|| unsafe {
-            self.alarms.write(Mutex::new([ALARM_NEW; ALARM_COUNT]));
+            self.alarms.write(Mutex::new(Vec::new()));
             self.zero_instant.write(StdInstant::now());
             self.signaler.write(Signaler::new());
 
@@ -69,7 +60,7 @@ impl TimeDriver {
             let mut next_alarm = u64::MAX;
             {
                 let alarms = &mut *unsafe { DRIVER.alarms.as_ref() }.lock().unwrap();
-                for alarm in alarms {
+                for alarm in alarms.iter_mut() {
                     if alarm.timestamp <= now {
                         alarm.timestamp = u64::MAX;
 
@@ -105,18 +96,17 @@ impl Driver for TimeDriver {
     }
 
     unsafe fn allocate_alarm(&self) -> Option<AlarmHandle> {
-        let id = self.alarm_count.fetch_update(Ordering::AcqRel, Ordering::Acquire, |x| {
-            if x < ALARM_COUNT as u8 {
-                Some(x + 1)
-            } else {
-                None
-            }
-        });
-
-        match id {
-            Ok(id) => Some(AlarmHandle::new(id)),
-            Err(_) => None,
+        self.init();
+        let mut alarms = unsafe { self.alarms.as_ref() }.lock().unwrap();
+        let id = alarms.len();
+        if id > u8::MAX as usize {
+            // AlarmHandle only has room for a u8 id; refuse rather than wrap around and alias
+            // an existing handle.
+            return None;
         }
+        alarms.push(AlarmState::new());
+
+        Some(AlarmHandle::new(id as u8))
     }
 
     fn set_alarm_callback(&self, alarm: AlarmHandle, callback: fn(*mut ()), ctx: *mut ()) {
@@ -208,3 +198,23 @@ impl<T: Copy> UninitCell<T> {
         ptr::read(self.as_mut_ptr())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allocate_alarm_refuses_once_ids_would_exceed_u8_max() {
+        // DRIVER is a process-wide singleton, so don't assume a zero starting count here; just
+        // keep allocating until it refuses, which must happen well before id 300 once ids would
+        // otherwise wrap past u8::MAX.
+        let mut refused = false;
+        for _ in 0..300 {
+            if unsafe { DRIVER.allocate_alarm() }.is_none() {
+                refused = true;
+                break;
+            }
+        }
+        assert!(refused, "allocate_alarm should refuse once alarm ids would exceed u8::MAX");
+    }
+}